@@ -34,10 +34,7 @@ use std::sync::Arc;
 /// builder.values().append_value(1);
 /// builder.values().append_value(2);
 /// builder.append(true);
-/// builder.values().append_null();
-/// builder.values().append_null();
-/// builder.values().append_null();
-/// builder.append(false);
+/// builder.append_null();
 /// builder.values().append_value(3);
 /// builder.values().append_null();
 /// builder.values().append_value(5);
@@ -68,6 +65,7 @@ pub struct FixedSizeListBuilder<T: ArrayBuilder> {
     values_builder: T,
     list_len: i32,
     field: Option<FieldRef>,
+    validation: bool,
 }
 
 impl<T: ArrayBuilder> FixedSizeListBuilder<T> {
@@ -91,9 +89,23 @@ impl<T: ArrayBuilder> FixedSizeListBuilder<T> {
             values_builder,
             list_len: value_length,
             field: None,
+            validation: false,
         }
     }
 
+    /// Enable or disable mid-build slot validation.
+    ///
+    /// By default the builder trusts the caller and only asserts the total child
+    /// length in [`finish`](Self::finish), so pushing the wrong number of values
+    /// for an interior slot can corrupt the array silently when the totals still
+    /// line up. With validation enabled each [`append`](Self::append) verifies
+    /// that the slot contributed exactly [`value_length`](Self::value_length)
+    /// child values, and [`finish`](Self::finish) / [`finish_cloned`](Self::finish_cloned)
+    /// build through the checked [`ArrayData::build`] path.
+    pub fn with_validation(self, validation: bool) -> Self {
+        Self { validation, ..self }
+    }
+
     /// Override the field passed to [`ArrayData::builder`]
     ///
     /// By default a nullable field is created with the name `item`
@@ -132,6 +144,11 @@ where
         self.null_buffer_builder.len()
     }
 
+    /// Appends a null slot, self-filling the child builder.
+    fn append_null(&mut self) {
+        FixedSizeListBuilder::append_null(self)
+    }
+
     /// Builds the array and reset this builder.
     fn finish(&mut self) -> ArrayRef {
         Arc::new(self.finish())
@@ -163,9 +180,84 @@ where
     /// Finish the current fixed-length list array slot
     #[inline]
     pub fn append(&mut self, is_valid: bool) {
+        if self.validation {
+            let expected = (self.len() + 1) * self.list_len as usize;
+            let actual = self.values_builder.len();
+            assert_eq!(
+                actual, expected,
+                "FixedSizeListBuilder slot {} contains {} child values, expected {}",
+                self.len(),
+                actual - self.len() * self.list_len as usize,
+                self.list_len,
+            );
+        }
         self.null_buffer_builder.append(is_valid);
     }
 
+    /// Append a null list slot.
+    ///
+    /// This records an invalid slot and automatically advances the child
+    /// `values` builder by [`value_length`](Self::value_length) nulls, so the
+    /// caller does not have to push the placeholder entries by hand to keep the
+    /// child length invariant checked in [`finish`](Self::finish) intact.
+    ///
+    /// The child nulls are added through [`ArrayBuilder::append_nulls`]; this
+    /// panics if the child builder keeps the trait's default
+    /// [`ArrayBuilder::append_null`].
+    #[inline]
+    pub fn append_null(&mut self) {
+        self.values_builder.append_nulls(self.list_len as usize);
+        self.null_buffer_builder.append(false);
+    }
+
+    /// Append a complete, non-null list slot from `values`.
+    ///
+    /// The values are pushed into the child builder and the slot is delimited in
+    /// a single call. Exactly [`value_length`](Self::value_length) values must be
+    /// supplied; the count is checked here so a mismatch is reported against the
+    /// offending slot rather than surfacing as the aggregate length panic in
+    /// [`finish`](Self::finish).
+    ///
+    /// Requires the child builder to implement [`Extend`] for the supplied value
+    /// type.
+    pub fn append_value<V>(&mut self, values: impl IntoIterator<Item = V>)
+    where
+        T: Extend<V>,
+    {
+        let start = self.values_builder.len();
+        self.values_builder.extend(values);
+        let appended = self.values_builder.len() - start;
+        assert_eq!(
+            appended,
+            self.list_len as usize,
+            "FixedSizeListBuilder slot {} contains {} values, expected {}",
+            self.len(),
+            appended,
+            self.list_len,
+        );
+        self.null_buffer_builder.append(true);
+    }
+
+    /// Extend the builder with an iterator of optional list slots.
+    ///
+    /// Each `Some(values)` is appended as a complete slot via
+    /// [`append_value`](Self::append_value) and each `None` as a null slot via
+    /// [`append_null`](Self::append_null), so a whole [`FixedSizeListArray`] can
+    /// be built from `Vec<[i32; 3]>`-like data in one expression.
+    pub fn extend<V, S, I>(&mut self, iter: I)
+    where
+        T: Extend<V>,
+        S: IntoIterator<Item = V>,
+        I: IntoIterator<Item = Option<S>>,
+    {
+        for slot in iter {
+            match slot {
+                Some(values) => self.append_value(values),
+                None => self.append_null(),
+            }
+        }
+    }
+
     /// Builds the [`FixedSizeListBuilder`] and reset this builder.
     pub fn finish(&mut self) -> FixedSizeListArray {
         let len = self.len();
@@ -207,7 +299,11 @@ where
             .add_child_data(values_data)
             .nulls(nulls);
 
-        let array_data = unsafe { array_data.build_unchecked() };
+        let array_data = if self.validation {
+            array_data.build().expect("Invalid FixedSizeListArray")
+        } else {
+            unsafe { array_data.build_unchecked() }
+        };
 
         FixedSizeListArray::from(array_data)
     }
@@ -253,7 +349,11 @@ where
             .add_child_data(values_data)
             .nulls(nulls);
 
-        let array_data = unsafe { array_data.build_unchecked() };
+        let array_data = if self.validation {
+            array_data.build().expect("Invalid FixedSizeListArray")
+        } else {
+            unsafe { array_data.build_unchecked() }
+        };
 
         FixedSizeListArray::from(array_data)
     }
@@ -298,6 +398,81 @@ mod tests {
         assert_eq!(3, list_array.value_length());
     }
 
+    #[test]
+    fn test_fixed_size_list_array_builder_append_null() {
+        let values_builder = Int32Builder::new();
+        let mut builder = FixedSizeListBuilder::new(values_builder, 3);
+
+        //  [[0, 1, 2], null, [3, null, 5]]
+        builder.values().append_value(0);
+        builder.values().append_value(1);
+        builder.values().append_value(2);
+        builder.append(true);
+        builder.append_null();
+        builder.values().append_value(3);
+        builder.values().append_null();
+        builder.values().append_value(5);
+        builder.append(true);
+        let list_array = builder.finish();
+
+        assert_eq!(DataType::Int32, list_array.value_type());
+        assert_eq!(3, list_array.len());
+        assert_eq!(1, list_array.null_count());
+        assert!(list_array.is_null(1));
+        assert_eq!(6, list_array.value_offset(2));
+        assert_eq!(3, list_array.value_length());
+    }
+
+    #[test]
+    fn test_fixed_size_list_array_builder_append_value() {
+        let values_builder = Int32Builder::new();
+        let mut builder = FixedSizeListBuilder::new(values_builder, 3);
+
+        //  [[0, 1, 2], [3, null, 5]]
+        builder.append_value([Some(0), Some(1), Some(2)]);
+        builder.append_value([Some(3), None, Some(5)]);
+        let list_array = builder.finish();
+
+        assert_eq!(2, list_array.len());
+        assert_eq!(0, list_array.null_count());
+        assert_eq!(
+            *list_array.value(1),
+            Int32Array::from(vec![Some(3), None, Some(5)])
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "FixedSizeListBuilder slot 1 contains 2 values, expected 3")]
+    fn test_fixed_size_list_array_builder_append_value_wrong_len() {
+        let values_builder = Int32Builder::new();
+        let mut builder = FixedSizeListBuilder::new(values_builder, 3);
+
+        builder.append_value([Some(0), Some(1), Some(2)]);
+        builder.append_value([Some(3), Some(4)]);
+    }
+
+    #[test]
+    fn test_fixed_size_list_array_builder_extend() {
+        let values_builder = Int32Builder::new();
+        let mut builder = FixedSizeListBuilder::new(values_builder, 3);
+
+        //  [[0, 1, 2], null, [6, 7, 8]]
+        builder.extend([
+            Some([Some(0), Some(1), Some(2)]),
+            None,
+            Some([Some(6), Some(7), Some(8)]),
+        ]);
+        let list_array = builder.finish();
+
+        assert_eq!(3, list_array.len());
+        assert_eq!(1, list_array.null_count());
+        assert!(list_array.is_null(1));
+        assert_eq!(
+            *list_array.value(2),
+            Int32Array::from(vec![Some(6), Some(7), Some(8)])
+        );
+    }
+
     #[test]
     fn test_fixed_size_list_array_builder_with_field() {
         let values_builder = Int32Builder::new();
@@ -514,6 +689,38 @@ mod tests {
         assert_eq!(3, list_array.value_length());
     }
 
+    #[test]
+    fn test_fixed_size_list_array_builder_with_validation() {
+        let values_builder = Int32Builder::new();
+        let mut builder = FixedSizeListBuilder::new(values_builder, 3).with_validation(true);
+
+        builder.values().append_slice(&[0, 1, 2]);
+        builder.append(true);
+        builder.values().append_slice(&[3, 4, 5]);
+        builder.append(true);
+        let list_array = builder.finish();
+
+        assert_eq!(2, list_array.len());
+        assert_eq!(3, list_array.value_length());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "FixedSizeListBuilder slot 0 contains 2 child values, expected 3"
+    )]
+    fn test_fixed_size_list_array_builder_with_validation_interior_slot() {
+        let values_builder = Int32Builder::new();
+        let mut builder = FixedSizeListBuilder::new(values_builder, 3).with_validation(true);
+
+        // Without validation, a short slot here (2 values) followed by a
+        // compensating-long slot (4 values) would make the child total 6 and
+        // slip past finish()'s aggregate check straight into build_unchecked.
+        // With validation on, the short slot is rejected at its own append(true),
+        // before the compensating slot is ever reached.
+        builder.values().append_slice(&[0, 1]);
+        builder.append(true);
+    }
+
     #[test]
     fn test_fixed_size_list_array_builder_empty() {
         let values_builder = Int32Array::builder(5);